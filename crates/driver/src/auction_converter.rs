@@ -1,8 +1,10 @@
 use anyhow::{Context, Result};
 use contracts::WETH9;
-use gas_estimation::GasPriceEstimating;
+use ethcontract::H256;
+use gas_estimation::{GasPrice1559, GasPriceEstimating};
 use model::auction::Auction as AuctionModel;
 use primitive_types::H160;
+use shared::{conversions::U256Ext as _, current_block::CurrentBlockStream};
 use solver::{
     liquidity::order_converter::OrderConverter, settlement::external_prices::ExternalPrices,
     solver::Auction,
@@ -10,7 +12,7 @@ use solver::{
 use std::{
     sync::{
         atomic::{AtomicU64, Ordering},
-        Arc,
+        Arc, Mutex,
     },
     time::{Duration, Instant},
 };
@@ -19,6 +21,63 @@ use std::{
 /// Determines how much time a solver has to compute solutions for an incoming `Auction`.
 const RUN_DURATION: Duration = Duration::from_secs(15);
 
+/// Errors converting an `Auction` that callers may want to react to
+/// specifically, as opposed to every failure being an opaque [`anyhow::Error`].
+#[derive(Debug, thiserror::Error)]
+pub enum AuctionError {
+    #[error("auction contains no user orders whose fee covers the minimum effective gas price")]
+    NoViableOrders,
+}
+
+/// Controls how the gas price quoted to solvers is derived from the current
+/// EIP-1559 fee estimate, and how tightly the solving `deadline` is scaled in
+/// response: a basis that tracks the priority fee more closely also shortens
+/// the window, since a rising priority fee signals contention for the next
+/// few blocks.
+#[derive(Clone, Copy, Debug)]
+pub enum PriorityFeeMode {
+    /// `base_fee_per_gas` plus the given percentage of it as headroom.
+    BaseFeePercentage(u64),
+    /// `base_fee_per_gas` plus `max_priority_fee_per_gas` increased by the
+    /// given percentage.
+    PriorityFeeIncreasePercentage(u64),
+}
+
+impl PriorityFeeMode {
+    /// Derives the gas price to quote to solvers for the given EIP-1559 fee
+    /// estimate.
+    fn effective_gas_price(self, gas_price: GasPrice1559) -> f64 {
+        match self {
+            Self::BaseFeePercentage(percentage) => {
+                gas_price.base_fee_per_gas * (1. + percentage as f64 / 100.)
+            }
+            Self::PriorityFeeIncreasePercentage(percentage) => {
+                gas_price.base_fee_per_gas
+                    + gas_price.max_priority_fee_per_gas * (1. + percentage as f64 / 100.)
+            }
+        }
+    }
+
+    /// Scales [`RUN_DURATION`] down as the priority fee rises relative to the
+    /// base fee, giving solvers less time to optimize during a congested,
+    /// high-priority-fee auction and the full window during calm periods.
+    /// `PriorityFeeIncreasePercentage` tracks the priority fee directly, so
+    /// it also reacts to congestion more sharply than `BaseFeePercentage`,
+    /// which quotes its headroom off the (comparatively stable) base fee.
+    fn deadline(self, gas_price: GasPrice1559) -> Duration {
+        let congestion = if gas_price.base_fee_per_gas > 0. {
+            (gas_price.max_priority_fee_per_gas / gas_price.base_fee_per_gas).clamp(0., 1.)
+        } else {
+            0.
+        };
+        let sensitivity = match self {
+            Self::BaseFeePercentage(_) => 0.5,
+            Self::PriorityFeeIncreasePercentage(_) => 0.75,
+        };
+        RUN_DURATION.mul_f64(1. - congestion * sensitivity)
+    }
+}
+
 #[cfg_attr(test, mockall::automock)]
 #[async_trait::async_trait]
 pub trait AuctionConverting: Send + Sync {
@@ -30,6 +89,24 @@ pub struct AuctionConverter {
     pub gas_price_estimator: Arc<dyn GasPriceEstimating>,
     pub native_token: H160,
     pub run: AtomicU64,
+    /// Floor under the estimated effective gas price. Used both as the basis
+    /// for gating out orders whose fee no longer covers their own execution
+    /// cost, and as the gas price handed to solvers, so a transient
+    /// underestimate doesn't let solvers spend compute on economically dead
+    /// auctions during a gas spike.
+    pub min_effective_gas_price: f64,
+    pub priority_fee_mode: PriorityFeeMode,
+    /// Rough upper bound on the gas a single user order adds to a
+    /// settlement, used to estimate whether an order's fee still covers its
+    /// own execution cost at the current gas price.
+    pub expected_settlement_gas: u64,
+    block_stream: CurrentBlockStream,
+    /// The block hash the previous `convert_auction` call observed, kept only
+    /// so a reorg can be logged for operational visibility. `run` is already
+    /// a strictly increasing counter bumped on every call, so it alone is
+    /// sufficient for callers to treat a later run as superseding an earlier
+    /// one; this field doesn't gate or invalidate anything.
+    last_observed_head: Mutex<Option<H256>>,
 }
 
 impl AuctionConverter {
@@ -37,6 +114,10 @@ impl AuctionConverter {
         native_token: WETH9,
         gas_price_estimator: Arc<dyn GasPriceEstimating>,
         fee_objective_scaling_factor: f64,
+        min_effective_gas_price: f64,
+        priority_fee_mode: PriorityFeeMode,
+        expected_settlement_gas: u64,
+        block_stream: CurrentBlockStream,
     ) -> Self {
         Self {
             order_converter: OrderConverter {
@@ -46,6 +127,11 @@ impl AuctionConverter {
             gas_price_estimator,
             native_token: native_token.address(),
             run: AtomicU64::default(),
+            min_effective_gas_price,
+            priority_fee_mode,
+            expected_settlement_gas,
+            block_stream,
+            last_observed_head: Mutex::default(),
         }
     }
 }
@@ -53,7 +139,33 @@ impl AuctionConverter {
 #[async_trait::async_trait]
 impl AuctionConverting for AuctionConverter {
     async fn convert_auction(&self, auction: AuctionModel) -> Result<Auction> {
+        let current_block = self.block_stream.borrow().clone();
+        let current_block_hash = current_block
+            .hash
+            .context("current block is missing its hash")?;
+        // A reorg between the previous call and this one doesn't need special
+        // handling here: `run` already strictly increases on every call, so
+        // solvers still computing against an orphaned fork produce solutions
+        // tagged with a `run` the driver will no longer treat as current.
+        // We still log it, since it's useful operationally to know when it
+        // happens.
+        let mut last_observed_head = self.last_observed_head.lock().unwrap();
+        if let Some(previous_head) = *last_observed_head {
+            if previous_head != current_block_hash
+                && previous_head != current_block.parent_hash
+            {
+                tracing::warn!(
+                    ?previous_head,
+                    new_head = ?current_block_hash,
+                    "observed chain head did not extend the previously observed head; possible reorg",
+                );
+            }
+        }
+        *last_observed_head = Some(current_block_hash);
+        drop(last_observed_head);
+
         let run = self.run.fetch_add(1, Ordering::SeqCst);
+
         let orders = auction
             .orders
             .into_iter()
@@ -86,15 +198,58 @@ impl AuctionConverting for AuctionConverter {
             .estimate()
             .await
             .context("failed to estimate gas price")?;
-        tracing::debug!("solving with gas price of {:?}", gas_price);
+        let effective_gas_price = self
+            .priority_fee_mode
+            .effective_gas_price(gas_price)
+            .max(self.min_effective_gas_price);
+        let deadline = Instant::now() + self.priority_fee_mode.deadline(gas_price);
+        tracing::debug!(
+            ?gas_price,
+            effective_gas_price,
+            priority_fee_mode = ?self.priority_fee_mode,
+            "solving with gas price floored at {}",
+            effective_gas_price
+        );
+
+        let min_order_fee = num::BigRational::from_float(
+            effective_gas_price * self.expected_settlement_gas as f64,
+        )
+        .unwrap_or_default();
+        let orders: Vec<_> = orders
+            .into_iter()
+            .filter(|order| {
+                // The floor only gates user orders: liquidity orders don't pay
+                // their own settlement gas, so their fee isn't meaningful here.
+                if order.is_liquidity_order {
+                    return true;
+                }
+                let native_fee = external_prices
+                    .price(&order.sell_token)
+                    .map(|price| order.scaled_unsubsidized_fee.to_big_rational() * price);
+                let viable = native_fee.as_ref().is_some_and(|fee| *fee >= min_order_fee);
+                if !viable {
+                    tracing::debug!(
+                        sell_token = ?order.sell_token,
+                        buy_token = ?order.buy_token,
+                        ?native_fee,
+                        ?min_order_fee,
+                        "dropping order below minimum effective gas price floor"
+                    );
+                }
+                viable
+            })
+            .collect();
+        if !orders.iter().any(|o| !o.is_liquidity_order) {
+            return Err(AuctionError::NoViableOrders.into());
+        }
 
         Ok(Auction {
             id: auction.next_solver_competition,
             run,
             orders,
             liquidity: vec![],
-            gas_price: gas_price.effective_gas_price(),
-            deadline: Instant::now() + RUN_DURATION,
+            gas_price: effective_gas_price,
+            deadline,
             external_prices,
         })
     }
@@ -103,6 +258,7 @@ impl AuctionConverting for AuctionConverter {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use ethcontract::web3::types::Block;
     use gas_estimation::GasPrice1559;
     use maplit::btreemap;
     use model::order::{Order, OrderData, OrderMetadata, BUY_ETH_ADDRESS};
@@ -111,6 +267,14 @@ mod tests {
     use shared::dummy_contract;
     use shared::gas_price_estimation::FakeGasPriceEstimator;
 
+    fn block_stream_at(hash: u8) -> CurrentBlockStream {
+        let block = Block {
+            hash: Some(H256::from_low_u64_be(hash as u64)),
+            ..Default::default()
+        };
+        tokio::sync::watch::channel(block).1
+    }
+
     #[tokio::test]
     async fn converts_auction() {
         let token = H160::from_low_u64_be;
@@ -137,7 +301,15 @@ mod tests {
         };
         let gas_estimator = Arc::new(FakeGasPriceEstimator::new(gas_price));
         let native_token = dummy_contract!(WETH9, token(1));
-        let converter = AuctionConverter::new(native_token.clone(), gas_estimator, 2.);
+        let converter = AuctionConverter::new(
+            native_token.clone(),
+            gas_estimator,
+            2.,
+            0.,
+            PriorityFeeMode::BaseFeePercentage(0),
+            300_000,
+            block_stream_at(1),
+        );
         let mut model = AuctionModel {
             block: 1,
             latest_settlement_block: 2,
@@ -183,4 +355,188 @@ mod tests {
         model.orders[0].metadata.is_liquidity_order = true;
         assert!(converter.convert_auction(model).await.is_err());
     }
+
+    #[tokio::test]
+    async fn drops_orders_that_no_longer_cover_gas_at_the_floor() {
+        let token = H160::from_low_u64_be;
+        let order = |sell_token, buy_token| Order {
+            data: OrderData {
+                sell_token: token(sell_token),
+                buy_token: token(buy_token),
+                buy_amount: 10.into(),
+                sell_amount: 10.into(),
+                partially_fillable: true,
+                ..Default::default()
+            },
+            metadata: OrderMetadata {
+                full_fee_amount: 100.into(),
+                executed_buy_amount: 1u8.into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let gas_price = GasPrice1559 {
+            base_fee_per_gas: 0.0,
+            max_fee_per_gas: 10000.0,
+            max_priority_fee_per_gas: 10000.0,
+        };
+        let gas_estimator = Arc::new(FakeGasPriceEstimator::new(gas_price));
+        let native_token = dummy_contract!(WETH9, token(1));
+        // A high floor means even a generously-scaled fee can't cover
+        // `expected_settlement_gas` worth of gas, so every order should be dropped.
+        let converter = AuctionConverter::new(
+            native_token,
+            gas_estimator,
+            2.,
+            1.,
+            PriorityFeeMode::BaseFeePercentage(0),
+            300_000,
+            block_stream_at(1),
+        );
+        let model = AuctionModel {
+            block: 1,
+            latest_settlement_block: 2,
+            next_solver_competition: 3,
+            orders: vec![order(1, 2), order(2, 3)],
+            prices: btreemap! { token(2) => U256::exp10(18), token(3) => U256::exp10(18) },
+        };
+
+        let err = converter.convert_auction(model).await.unwrap_err();
+        assert!(err.downcast_ref::<AuctionError>().is_some());
+    }
+
+    #[tokio::test]
+    async fn gas_price_floor_exempts_liquidity_orders() {
+        let token = H160::from_low_u64_be;
+        let order = |sell_token, buy_token, is_liquidity_order| Order {
+            data: OrderData {
+                sell_token: token(sell_token),
+                buy_token: token(buy_token),
+                buy_amount: 10.into(),
+                sell_amount: 10.into(),
+                partially_fillable: true,
+                ..Default::default()
+            },
+            metadata: OrderMetadata {
+                full_fee_amount: 100.into(),
+                executed_buy_amount: 1u8.into(),
+                is_liquidity_order,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let gas_price = GasPrice1559 {
+            base_fee_per_gas: 0.0,
+            max_fee_per_gas: 10000.0,
+            max_priority_fee_per_gas: 10000.0,
+        };
+        let gas_estimator = Arc::new(FakeGasPriceEstimator::new(gas_price));
+        let native_token = dummy_contract!(WETH9, token(1));
+        // The floor is trivial for a priced order to clear, but the
+        // liquidity order's sell token (99) has no price at all, which would
+        // fail the floor check if it were applied to liquidity orders too.
+        let converter = AuctionConverter::new(
+            native_token,
+            gas_estimator,
+            2.,
+            0.,
+            PriorityFeeMode::BaseFeePercentage(0),
+            300_000,
+            block_stream_at(1),
+        );
+        let model = AuctionModel {
+            block: 1,
+            latest_settlement_block: 2,
+            next_solver_competition: 3,
+            orders: vec![order(1, 2, false), order(99, 3, true)],
+            prices: btreemap! { token(2) => U256::exp10(18), token(3) => U256::exp10(18) },
+        };
+
+        let auction = converter.convert_auction(model).await.unwrap();
+        assert_eq!(auction.orders.len(), 2);
+        assert!(auction.orders.iter().any(|o| o.sell_token == token(99)));
+    }
+
+    #[tokio::test]
+    async fn run_strictly_increases_across_a_reorg() {
+        let token = H160::from_low_u64_be;
+        let gas_price = GasPrice1559 {
+            base_fee_per_gas: 0.0,
+            max_fee_per_gas: 10000.0,
+            max_priority_fee_per_gas: 10000.0,
+        };
+        let gas_estimator = Arc::new(FakeGasPriceEstimator::new(gas_price));
+        let native_token = dummy_contract!(WETH9, token(1));
+        let block_at = |hash: u8| Block {
+            hash: Some(H256::from_low_u64_be(hash as u64)),
+            ..Default::default()
+        };
+        let (block_sender, block_stream) = tokio::sync::watch::channel(block_at(1));
+        let converter = AuctionConverter::new(
+            native_token,
+            gas_estimator,
+            2.,
+            0.,
+            PriorityFeeMode::BaseFeePercentage(0),
+            300_000,
+            block_stream,
+        );
+        let model = AuctionModel {
+            block: 1,
+            latest_settlement_block: 1,
+            next_solver_competition: 1,
+            orders: vec![],
+            prices: Default::default(),
+        };
+
+        // No orders, so this errors out, but `run` is bumped regardless.
+        let _ = converter.convert_auction(model.clone()).await;
+        let run_before = converter.run.load(Ordering::SeqCst);
+
+        // A reorg retracts the previous head in favor of a sibling block.
+        block_sender.send(block_at(2)).unwrap();
+        let _ = converter.convert_auction(model).await;
+        let run_after = converter.run.load(Ordering::SeqCst);
+
+        assert!(run_after > run_before);
+    }
+
+    #[test]
+    fn priority_fee_mode_derives_gas_price_and_shrinks_deadline_under_congestion() {
+        let gas_price = GasPrice1559 {
+            base_fee_per_gas: 100.,
+            max_priority_fee_per_gas: 100.,
+            max_fee_per_gas: 300.,
+        };
+
+        assert_eq!(
+            PriorityFeeMode::BaseFeePercentage(50).effective_gas_price(gas_price),
+            150.
+        );
+        assert_eq!(
+            PriorityFeeMode::PriorityFeeIncreasePercentage(50).effective_gas_price(gas_price),
+            100. + 150.
+        );
+
+        // Maximally congested: priority fee matches the base fee, shrinking
+        // the window by each mode's own sensitivity.
+        assert_eq!(
+            PriorityFeeMode::BaseFeePercentage(0).deadline(gas_price),
+            RUN_DURATION.mul_f64(0.5)
+        );
+        assert_eq!(
+            PriorityFeeMode::PriorityFeeIncreasePercentage(0).deadline(gas_price),
+            RUN_DURATION.mul_f64(0.25)
+        );
+        // Calm network: no priority fee means the full window, regardless of mode.
+        let calm = GasPrice1559 {
+            max_priority_fee_per_gas: 0.,
+            ..gas_price
+        };
+        assert_eq!(PriorityFeeMode::BaseFeePercentage(0).deadline(calm), RUN_DURATION);
+        assert_eq!(
+            PriorityFeeMode::PriorityFeeIncreasePercentage(0).deadline(calm),
+            RUN_DURATION
+        );
+    }
 }
\ No newline at end of file
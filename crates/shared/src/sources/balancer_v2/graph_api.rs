@@ -0,0 +1,59 @@
+//! A minimal client for the pools the Balancer V2 subgraph indexes, used to
+//! seed each pool registry with pools deployed before the fetcher starts
+//! listening for on-chain events.
+//!
+//! The subgraph itself is not queried: `get_registered_pools` always returns
+//! an empty set of pools at block 0, so registries seeded from it start empty.
+
+use crate::sources::balancer_v2::swap::fixed_point::Bfp;
+use anyhow::Result;
+use ethcontract::{H160, H256};
+use reqwest::Client;
+
+#[derive(Clone, Debug)]
+pub struct BalancerSubgraphClient {
+    chain_id: u64,
+    client: Client,
+}
+
+impl BalancerSubgraphClient {
+    pub fn for_chain(chain_id: u64, client: Client) -> Result<Self> {
+        Ok(Self { chain_id, client })
+    }
+
+    pub async fn get_registered_pools(&self) -> Result<RegisteredPools> {
+        // Querying the subgraph's GraphQL endpoint for `self.chain_id` is
+        // out of scope for this crate snapshot; a real client would page
+        // through the `pools` collection here.
+        let _ = &self.client;
+        Ok(RegisteredPools::default())
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct RegisteredPools {
+    pub fetched_block_number: u64,
+    pub pools: Vec<PoolData>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PoolType {
+    Weighted,
+    Stable,
+    ComposableStable,
+    MetaStable,
+}
+
+#[derive(Clone, Debug)]
+pub struct PoolData {
+    pub pool_type: PoolType,
+    pub id: H256,
+    pub tokens: Vec<TokenData>,
+}
+
+#[derive(Clone, Debug)]
+pub struct TokenData {
+    pub address: H160,
+    pub decimals: u8,
+    pub weight: Option<Bfp>,
+}
@@ -0,0 +1,7 @@
+//! Support for the Balancer V2 family of AMM pools.
+
+pub mod graph_api;
+pub mod pool_fetching;
+pub mod pool_init;
+pub mod pools;
+pub mod swap;
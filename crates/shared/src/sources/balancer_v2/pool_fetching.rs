@@ -1,7 +1,12 @@
 //! Pool Fetching is primarily concerned with retrieving relevant pools from the `BalancerPoolRegistry`
 //! when given a collection of `TokenPair`. Each of these pools are then queried for
-//! their `token_balances` and the `PoolFetcher` returns all up-to-date `Weighted` and `Stable`
-//! pools to be consumed by external users (e.g. Price Estimators and Solvers).
+//! their `token_balances` and the `PoolFetcher` returns all up-to-date `Weighted`, `Stable`,
+//! `ComposableStable` and `MetaStable` pools to be consumed by external users (e.g. Price
+//! Estimators and Solvers).
+//!
+//! `aggregate`, `cache`, `internal` and `registry` are the event-driven side of this module and
+//! are out of scope for the `ComposableStable`/`MetaStable` work; they're left as declared
+//! submodules pending their own implementation.
 
 mod aggregate;
 mod cache;
@@ -30,6 +35,7 @@ use crate::{
 };
 use anyhow::Result;
 use contracts::{
+    BalancerV2ComposableStablePoolFactory, BalancerV2MetaStablePoolFactory,
     BalancerV2StablePoolFactory, BalancerV2Vault, BalancerV2WeightedPool2TokensFactory,
     BalancerV2WeightedPoolFactory,
 };
@@ -99,10 +105,78 @@ impl StablePool {
     }
 }
 
+/// A MetaStable pool, unlike `ComposableStablePool`, does not register its
+/// own BPT as one of the Vault's tokens, so its reserves need no filtering.
+#[derive(Clone, Debug)]
+pub struct MetaStablePool {
+    pub common: CommonPoolState,
+    pub reserves: HashMap<H160, TokenState>,
+    pub amplification_parameter: AmplificationParameter,
+}
+
+impl MetaStablePool {
+    pub fn new_unpaused(pool_id: H256, stable_state: stable::PoolState) -> Self {
+        MetaStablePool {
+            common: CommonPoolState {
+                id: pool_id,
+                address: pool_address_from_id(pool_id),
+                swap_fee: stable_state.swap_fee,
+                paused: false,
+            },
+            reserves: stable_state.tokens.into_iter().collect(),
+            amplification_parameter: stable_state.amplification_parameter,
+        }
+    }
+}
+
+/// A ComposableStable pool registers its own BPT as one of the Vault's
+/// tokens, which must be excluded from the stable-math invariant and from
+/// pricing, since it isn't a token the pool actually swaps against.
+#[derive(Clone, Debug)]
+pub struct ComposableStablePool {
+    pub common: CommonPoolState,
+    pub reserves: HashMap<H160, TokenState>,
+    pub amplification_parameter: AmplificationParameter,
+    /// The pool's own BPT token, whose address is the pool's address.
+    pub bpt_token: H160,
+}
+
+impl ComposableStablePool {
+    pub fn new_unpaused(pool_id: H256, stable_state: stable::PoolState) -> Self {
+        let bpt_token = pool_address_from_id(pool_id);
+        ComposableStablePool {
+            common: CommonPoolState {
+                id: pool_id,
+                address: bpt_token,
+                swap_fee: stable_state.swap_fee,
+                paused: false,
+            },
+            reserves: stable_state
+                .tokens
+                .into_iter()
+                .filter(|(token, _)| *token != bpt_token)
+                .collect(),
+            amplification_parameter: stable_state.amplification_parameter,
+            bpt_token,
+        }
+    }
+
+    /// The pool's reserves relevant for pricing, i.e. everything but its own
+    /// BPT, which is excluded from the stable-math invariant.
+    fn non_bpt_tokens(&self) -> impl Iterator<Item = H160> + '_ {
+        self.reserves
+            .keys()
+            .copied()
+            .filter(move |token| *token != self.bpt_token)
+    }
+}
+
 #[derive(Default)]
 pub struct FetchedBalancerPools {
     pub stable_pools: Vec<StablePool>,
     pub weighted_pools: Vec<WeightedPool>,
+    pub composable_stable_pools: Vec<ComposableStablePool>,
+    pub meta_stable_pools: Vec<MetaStablePool>,
 }
 
 impl FetchedBalancerPools {
@@ -118,6 +192,16 @@ impl FetchedBalancerPools {
                 .iter()
                 .flat_map(|pool| pool.reserves.keys().copied()),
         );
+        tokens.extend(
+            self.composable_stable_pools
+                .iter()
+                .flat_map(|pool| pool.non_bpt_tokens()),
+        );
+        tokens.extend(
+            self.meta_stable_pools
+                .iter()
+                .flat_map(|pool| pool.reserves.keys().copied()),
+        );
         tokens
     }
 }
@@ -192,6 +276,12 @@ impl BalancerPoolFetching for BalancerPoolFetcher {
                     PoolKind::Stable(state) => fetched_pools
                         .stable_pools
                         .push(StablePool::new_unpaused(pool.id, state)),
+                    PoolKind::ComposableStable(state) => fetched_pools
+                        .composable_stable_pools
+                        .push(ComposableStablePool::new_unpaused(pool.id, state)),
+                    PoolKind::MetaStable(state) => fetched_pools
+                        .meta_stable_pools
+                        .push(MetaStablePool::new_unpaused(pool.id, state)),
                 }
                 fetched_pools
             },
@@ -218,6 +308,9 @@ async fn create_all_pool_fetchers(
     let weighted_pool_factory = BalancerV2WeightedPoolFactory::deployed(&web3).await?;
     let two_token_pool_factory = BalancerV2WeightedPool2TokensFactory::deployed(&web3).await?;
     let stable_pool_factory = BalancerV2StablePoolFactory::deployed(&web3).await?;
+    let composable_stable_pool_factory =
+        BalancerV2ComposableStablePoolFactory::deployed(&web3).await?;
+    let meta_stable_pool_factory = BalancerV2MetaStablePoolFactory::deployed(&web3).await?;
 
     let initial_pools = pool_initializer.initialize_pools().await?;
     let start_sync_at_block = Some(initial_pools.fetched_block_number);
@@ -243,6 +336,11 @@ async fn create_all_pool_fetchers(
         create_pool_registry!(weighted_pool_factory, initial_pools.weighted_pools),
         create_pool_registry!(two_token_pool_factory, initial_pools.weighted_2token_pools),
         create_pool_registry!(stable_pool_factory, initial_pools.stable_pools),
+        create_pool_registry!(
+            composable_stable_pool_factory,
+            initial_pools.composable_stable_pools
+        ),
+        create_pool_registry!(meta_stable_pool_factory, initial_pools.meta_stable_pools),
     ]))
 }
 
@@ -348,6 +446,18 @@ mod tests {
                         assert_eq!(token_state.scaling_exponent, 18 - token.decimals);
                     }
                 }
+                PoolKind::ComposableStable(state) => {
+                    for token in &subgraph_pool.tokens {
+                        let token_state = &state.tokens[&token.address];
+                        assert_eq!(token_state.scaling_exponent, 18 - token.decimals);
+                    }
+                }
+                PoolKind::MetaStable(state) => {
+                    for token in &subgraph_pool.tokens {
+                        let token_state = &state.tokens[&token.address];
+                        assert_eq!(token_state.scaling_exponent, 18 - token.decimals);
+                    }
+                }
             };
         }
         tracing::warn!(?unknown_pools);
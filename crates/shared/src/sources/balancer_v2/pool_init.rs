@@ -0,0 +1,137 @@
+//! Seeds each pool registry with the pools already deployed before the
+//! fetcher starts listening for on-chain events, so that pools created
+//! before this process started aren't missed until their next
+//! state-changing event.
+
+use super::{
+    graph_api::{BalancerSubgraphClient, PoolData, PoolType},
+    pool_fetching::{ComposableStablePool, MetaStablePool, StablePool, WeightedPool},
+    pools::{common::TokenState, stable, weighted},
+    swap::fixed_point::Bfp,
+};
+use anyhow::Result;
+use ethcontract::{H160, U256};
+use reqwest::Client;
+use std::collections::HashMap;
+
+/// The pools known at startup, bucketed by kind so each registry can be
+/// seeded directly instead of replaying on-chain events for them.
+#[derive(Clone, Debug, Default)]
+pub struct InitializedPools {
+    pub fetched_block_number: u64,
+    pub weighted_pools: Vec<WeightedPool>,
+    pub weighted_2token_pools: Vec<WeightedPool>,
+    pub stable_pools: Vec<StablePool>,
+    pub composable_stable_pools: Vec<ComposableStablePool>,
+    pub meta_stable_pools: Vec<MetaStablePool>,
+}
+
+#[async_trait::async_trait]
+pub trait PoolInitializing: Send + Sync {
+    async fn initialize_pools(&self) -> Result<InitializedPools>;
+}
+
+pub struct SubgraphPoolInitializer {
+    client: BalancerSubgraphClient,
+}
+
+impl SubgraphPoolInitializer {
+    pub fn new(chain_id: u64, client: Client) -> Result<Self> {
+        Ok(Self {
+            client: BalancerSubgraphClient::for_chain(chain_id, client)?,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl PoolInitializing for SubgraphPoolInitializer {
+    async fn initialize_pools(&self) -> Result<InitializedPools> {
+        let registered = self.client.get_registered_pools().await?;
+        let mut pools = InitializedPools {
+            fetched_block_number: registered.fetched_block_number,
+            ..Default::default()
+        };
+
+        for pool in &registered.pools {
+            match pool.pool_type {
+                PoolType::Weighted => pools
+                    .weighted_pools
+                    .push(WeightedPool::new_unpaused(pool.id, weighted_state(pool))),
+                PoolType::Stable => pools
+                    .stable_pools
+                    .push(StablePool::new_unpaused(pool.id, stable_state(pool))),
+                PoolType::ComposableStable => pools.composable_stable_pools.push(
+                    ComposableStablePool::new_unpaused(pool.id, stable_state(pool)),
+                ),
+                PoolType::MetaStable => pools
+                    .meta_stable_pools
+                    .push(MetaStablePool::new_unpaused(pool.id, stable_state(pool))),
+            }
+        }
+
+        Ok(pools)
+    }
+}
+
+fn stable_state(pool: &PoolData) -> stable::PoolState {
+    stable::PoolState {
+        tokens: token_states(pool),
+        swap_fee: Bfp::default(),
+        amplification_parameter: stable::AmplificationParameter::new(U256::zero(), U256::one()),
+    }
+}
+
+fn weighted_state(pool: &PoolData) -> weighted::PoolState {
+    weighted::PoolState {
+        tokens: pool
+            .tokens
+            .iter()
+            .map(|token| {
+                (
+                    token.address,
+                    weighted::TokenState {
+                        common: TokenState {
+                            balance: U256::zero(),
+                            scaling_exponent: 18 - token.decimals,
+                        },
+                        weight: token.weight.unwrap_or_default(),
+                    },
+                )
+            })
+            .collect(),
+        swap_fee: Bfp::default(),
+    }
+}
+
+fn token_states(pool: &PoolData) -> HashMap<H160, TokenState> {
+    pool.tokens
+        .iter()
+        .map(|token| {
+            (
+                token.address,
+                TokenState {
+                    balance: U256::zero(),
+                    scaling_exponent: 18 - token.decimals,
+                },
+            )
+        })
+        .collect()
+}
+
+pub struct EmptyPoolInitializer {
+    chain_id: u64,
+}
+
+impl EmptyPoolInitializer {
+    pub fn for_chain(chain_id: u64) -> Self {
+        Self { chain_id }
+    }
+}
+
+#[async_trait::async_trait]
+impl PoolInitializing for EmptyPoolInitializer {
+    async fn initialize_pools(&self) -> Result<InitializedPools> {
+        let _ = self.chain_id;
+        Ok(InitializedPools::default())
+    }
+}
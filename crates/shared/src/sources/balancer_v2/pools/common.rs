@@ -0,0 +1,32 @@
+use crate::token_info::TokenInfoFetching;
+use ethcontract::U256;
+use std::sync::Arc;
+
+/// The balance and scaling state a pool tracks for one of its tokens.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TokenState {
+    pub balance: U256,
+    pub scaling_exponent: u8,
+}
+
+/// Fetches the on-chain state needed to price a pool for a given factory,
+/// normalizing decimals into this module's `TokenState`.
+pub struct PoolInfoFetcher<Factory> {
+    vault: contracts::BalancerV2Vault,
+    factory: Factory,
+    token_infos: Arc<dyn TokenInfoFetching>,
+}
+
+impl<Factory> PoolInfoFetcher<Factory> {
+    pub fn new(
+        vault: contracts::BalancerV2Vault,
+        factory: Factory,
+        token_infos: Arc<dyn TokenInfoFetching>,
+    ) -> Self {
+        Self {
+            vault,
+            factory,
+            token_infos,
+        }
+    }
+}
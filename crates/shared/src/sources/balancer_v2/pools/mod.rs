@@ -0,0 +1,28 @@
+//! Pool state definitions shared by every Balancer V2 pool fetcher.
+
+pub mod common;
+pub mod stable;
+pub mod weighted;
+
+use ethcontract::H256;
+
+/// A pool as surfaced by the registry-backed fetchers, tagged with its kind
+/// so callers can recover the pool-specific state and math.
+#[derive(Clone, Debug)]
+pub struct Pool {
+    pub id: H256,
+    pub kind: PoolKind,
+}
+
+/// The Balancer V2 pool kinds this crate knows how to price.
+#[derive(Clone, Debug)]
+pub enum PoolKind {
+    Weighted(weighted::PoolState),
+    Stable(stable::PoolState),
+    /// A Stable pool variant that registers its own BPT as a Vault token,
+    /// which must be excluded from the stable-math invariant.
+    ComposableStable(stable::PoolState),
+    /// A Stable pool variant that prices against other tokens without
+    /// registering its own BPT as a Vault token.
+    MetaStable(stable::PoolState),
+}
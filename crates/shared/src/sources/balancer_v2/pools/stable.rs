@@ -0,0 +1,33 @@
+use super::common::TokenState;
+use crate::sources::balancer_v2::swap::fixed_point::Bfp;
+use ethcontract::{H160, U256};
+use std::collections::HashMap;
+
+#[derive(Clone, Debug)]
+pub struct PoolState {
+    pub tokens: HashMap<H160, TokenState>,
+    pub swap_fee: Bfp,
+    pub amplification_parameter: AmplificationParameter,
+}
+
+/// The pool's amplification parameter, stored as the on-chain factor/
+/// precision pair so it can be scaled without losing precision.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AmplificationParameter {
+    factor: U256,
+    precision: U256,
+}
+
+impl AmplificationParameter {
+    pub fn new(factor: U256, precision: U256) -> Self {
+        Self { factor, precision }
+    }
+
+    pub fn factor(&self) -> U256 {
+        self.factor
+    }
+
+    pub fn precision(&self) -> U256 {
+        self.precision
+    }
+}
@@ -0,0 +1,16 @@
+use super::common;
+use crate::sources::balancer_v2::swap::fixed_point::Bfp;
+use ethcontract::H160;
+use std::collections::HashMap;
+
+#[derive(Clone, Debug)]
+pub struct PoolState {
+    pub tokens: HashMap<H160, TokenState>,
+    pub swap_fee: Bfp,
+}
+
+#[derive(Clone, Debug)]
+pub struct TokenState {
+    pub common: common::TokenState,
+    pub weight: Bfp,
+}
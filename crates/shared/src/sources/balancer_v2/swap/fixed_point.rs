@@ -0,0 +1,17 @@
+use ethcontract::U256;
+
+/// An 18-decimal fixed point number, matching the Vault's internal
+/// representation for swap fees, weights and amplification parameters.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Bfp(U256);
+
+impl Bfp {
+    /// Wraps a raw 18-decimal on-chain value.
+    pub fn from_wei(value: U256) -> Self {
+        Self(value)
+    }
+
+    pub fn as_uint256(&self) -> U256 {
+        self.0
+    }
+}
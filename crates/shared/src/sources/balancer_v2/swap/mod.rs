@@ -0,0 +1 @@
+pub mod fixed_point;